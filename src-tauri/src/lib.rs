@@ -1,5 +1,10 @@
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::Mutex;
+
+use chrono::{Local, NaiveDate};
+use tauri::Manager;
 
 /// Get the config directory path for storing app settings
 fn get_config_path() -> PathBuf {
@@ -10,31 +15,80 @@ fn get_config_path() -> PathBuf {
     config_dir.join("settings.json")
 }
 
+/// Where the journal directory is sourced from
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum JournalDirectoryMode {
+    /// Resolved at call time from the user's home/documents directory, so it follows
+    /// the user across machines instead of being pinned to a stored absolute path
+    Home,
+    /// An explicit, user-chosen path stored in `journal_directory_path`
+    Custom,
+}
+
+impl Default for JournalDirectoryMode {
+    fn default() -> Self {
+        JournalDirectoryMode::Home
+    }
+}
+
 /// App settings structure
-#[derive(serde::Serialize, serde::Deserialize, Default)]
+#[derive(serde::Serialize, serde::Deserialize, Default, Clone)]
 struct Settings {
-    journal_directory: Option<String>,
+    #[serde(default)]
+    journal_directory_mode: JournalDirectoryMode,
+    journal_directory_path: Option<String>,
     dark_mode: Option<bool>,
+    git_enabled: Option<bool>,
+    hour_format: Option<String>, // "12h" or "24h"
+    daily_filename_format: Option<String>,
+
+    /// Pre-chunk0-5 field. Migrated into `journal_directory_mode`/`journal_directory_path`
+    /// on load and never written back out, so it naturally disappears after the next save.
+    #[serde(rename = "journal_directory", skip_serializing)]
+    legacy_journal_directory: Option<String>,
+}
+
+/// Raw journal directory setting, as opposed to `resolve_journal_directory`'s resolved
+/// absolute path — lets a settings UI redisplay "home" vs "custom" instead of only ever
+/// seeing (and re-saving) a concrete path.
+#[derive(serde::Serialize, Clone)]
+struct JournalDirectorySetting {
+    mode: JournalDirectoryMode,
+    path: Option<String>,
 }
 
 fn load_settings() -> Settings {
     let config_path = get_config_path();
-    if config_path.exists() {
+    let mut settings: Settings = if config_path.exists() {
         fs::read_to_string(&config_path)
             .ok()
             .and_then(|content| serde_json::from_str(&content).ok())
             .unwrap_or_default()
     } else {
         Settings::default()
+    };
+
+    if let Some(legacy_directory) = settings.legacy_journal_directory.take() {
+        settings.journal_directory_mode = JournalDirectoryMode::Custom;
+        settings.journal_directory_path = Some(legacy_directory);
+        save_settings(&settings).ok();
     }
+
+    settings
 }
 
+/// Persist settings atomically: write to a temp file, then rename over the real one,
+/// so a crash mid-write can't leave `settings.json` truncated or corrupt.
 fn save_settings(settings: &Settings) -> Result<(), String> {
     let config_path = get_config_path();
+    let tmp_path = config_path.with_extension("json.tmp");
     let content = serde_json::to_string_pretty(settings)
         .map_err(|e| format!("Failed to serialize settings: {}", e))?;
-    fs::write(&config_path, content)
-        .map_err(|e| format!("Failed to write settings: {}", e))
+    fs::write(&tmp_path, content)
+        .map_err(|e| format!("Failed to write settings: {}", e))?;
+    fs::rename(&tmp_path, &config_path)
+        .map_err(|e| format!("Failed to persist settings: {}", e))
 }
 
 /// Journal entry metadata
@@ -44,6 +98,65 @@ struct JournalEntry {
     entry_type: String, // "daily" or "titled"
     title: String,
     date: Option<String>,
+    tags: Vec<String>,
+    created: Option<String>,
+    metadata: HashMap<String, String>,
+}
+
+/// YAML front matter block at the top of a journal entry. Custom metadata is kept as
+/// `serde_yaml::Value` rather than `String` so a single non-string scalar field (a bare
+/// number, bool, etc. — all common in hand-written front matter) can't fail the whole
+/// block's deserialize and silently drop `tags`/`created` along with it.
+#[derive(serde::Deserialize, Default)]
+struct FrontMatter {
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    created: Option<String>,
+    #[serde(flatten)]
+    metadata: HashMap<String, serde_yaml::Value>,
+}
+
+impl FrontMatter {
+    /// Stringify `metadata` for `JournalEntry`, which only needs display-ready values
+    fn metadata_as_strings(&self) -> HashMap<String, String> {
+        self.metadata
+            .iter()
+            .map(|(key, value)| (key.clone(), yaml_scalar_to_string(value)))
+            .collect()
+    }
+}
+
+/// Render a YAML value as a plain string for display, without failing on non-string scalars
+fn yaml_scalar_to_string(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::String(s) => s.clone(),
+        serde_yaml::Value::Bool(b) => b.to_string(),
+        serde_yaml::Value::Number(n) => n.to_string(),
+        serde_yaml::Value::Null => String::new(),
+        other => serde_yaml::to_string(other).unwrap_or_default().trim().to_string(),
+    }
+}
+
+/// Read the leading `--- ... ---` YAML front matter block from a journal entry, if present.
+/// Only the first ~2KB of the file is read, since front matter always sits at the top.
+fn read_front_matter(path: &PathBuf) -> FrontMatter {
+    use std::io::Read;
+
+    let mut buffer = [0u8; 2048];
+    let bytes_read = fs::File::open(path)
+        .and_then(|mut file| file.read(&mut buffer))
+        .unwrap_or(0);
+
+    let text = String::from_utf8_lossy(&buffer[..bytes_read]);
+    let Some(rest) = text.strip_prefix("---") else {
+        return FrontMatter::default();
+    };
+    let Some(end) = rest.find("\n---") else {
+        return FrontMatter::default();
+    };
+
+    serde_yaml::from_str(&rest[..end]).unwrap_or_default()
 }
 
 /// Check if a filename is a daily entry (YYYY-MM-DD.md format)
@@ -57,42 +170,268 @@ fn is_daily_entry(filename: &str) -> bool {
         && filename[8..10].chars().all(|c| c.is_ascii_digit())
 }
 
+/// A single commit touching a journal entry, as shown in its revision history
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct CommitInfo {
+    hash: String,
+    date: String,
+    message: String,
+}
+
+/// Run a `git` subcommand in the journal directory
+fn run_git(directory: &str, args: &[&str]) -> Result<std::process::Output, String> {
+    std::process::Command::new("git")
+        .args(args)
+        .current_dir(directory)
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))
+}
+
+/// Initialize the journal directory as a git repo if it isn't one already
+fn ensure_git_repo(directory: &str) -> Result<(), String> {
+    if PathBuf::from(directory).join(".git").exists() {
+        return Ok(());
+    }
+    let output = run_git(directory, &["init"])?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+    Ok(())
+}
+
+/// Stage `filename` and commit it with `message`, initializing the repo on first use.
+/// A resave of unchanged content (nothing staged after `git add`) is treated as success
+/// rather than propagating git's "nothing to commit" failure, as is deleting a file that
+/// was never tracked (e.g. it predates git being enabled) — `git add` reports a pathspec
+/// mismatch for that since the file is already gone from disk, but there's nothing to undo.
+fn commit_journal_change(directory: &str, filename: &str, message: &str) -> Result<(), String> {
+    ensure_git_repo(directory)?;
+
+    let add = run_git(directory, &["add", "--", filename])?;
+    if !add.status.success() {
+        let stderr = String::from_utf8_lossy(&add.stderr);
+        if stderr.contains("did not match any files") {
+            return Ok(());
+        }
+        return Err(stderr.to_string());
+    }
+
+    let status = run_git(directory, &["status", "--porcelain", "--", filename])?;
+    if !status.status.success() {
+        return Err(String::from_utf8_lossy(&status.stderr).to_string());
+    }
+    if status.stdout.is_empty() {
+        return Ok(());
+    }
+
+    let commit = run_git(directory, &["commit", "--quiet", "-m", message])?;
+    if !commit.status.success() {
+        return Err(String::from_utf8_lossy(&commit.stderr).to_string());
+    }
+    Ok(())
+}
+
+/// Compute a fast, non-cryptographic 64-bit FNV-1a hash over a byte slice, used to detect
+/// whether a journal file changed on disk since it was last loaded
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Hex-encode a journal entry's FNV-1a content hash for storage/comparison
+fn content_hash(bytes: &[u8]) -> String {
+    format!("{:016x}", fnv1a_hash(bytes))
+}
+
+/// A journal entry's content together with a hash of that content, used to detect
+/// external modifications before overwriting the file
+#[derive(serde::Serialize, Clone)]
+struct LoadedJournal {
+    content: String,
+    hash: String,
+}
+
+/// Result of a conflict-checked save: either it went through, or the file had
+/// changed on disk since it was loaded and the caller gets the current content back
+#[derive(serde::Serialize, Clone)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum SaveOutcome {
+    Saved,
+    Conflict {
+        current_content: String,
+        current_hash: String,
+    },
+}
+
 /// Save a journal entry to a file
 #[tauri::command]
-fn save_journal(filename: String, content: String, directory: String) -> Result<(), String> {
+fn save_journal(
+    filename: String,
+    content: String,
+    directory: String,
+    state: tauri::State<Mutex<Settings>>,
+) -> Result<(), String> {
     let path = PathBuf::from(&directory).join(&filename);
     fs::write(&path, content)
-        .map_err(|e| format!("Failed to save journal entry: {}", e))
+        .map_err(|e| format!("Failed to save journal entry: {}", e))?;
+
+    let git_enabled = state.lock().unwrap().git_enabled.unwrap_or(false);
+    if git_enabled {
+        commit_journal_change(&directory, &filename, &format!("update {}", filename))?;
+    }
+
+    Ok(())
 }
 
-/// Load a journal entry from a file
+/// Load a journal entry from a file, along with a hash of its content for conflict detection
 #[tauri::command]
-fn load_journal(filename: String, directory: String) -> Result<Option<String>, String> {
+fn load_journal(filename: String, directory: String) -> Result<Option<LoadedJournal>, String> {
     let path = PathBuf::from(&directory).join(&filename);
     if path.exists() {
-        fs::read_to_string(&path)
-            .map(Some)
-            .map_err(|e| format!("Failed to load journal entry: {}", e))
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to load journal entry: {}", e))?;
+        let hash = content_hash(content.as_bytes());
+        Ok(Some(LoadedJournal { content, hash }))
     } else {
         Ok(None)
     }
 }
 
+/// Save a journal entry, refusing to overwrite it if the on-disk content no longer matches
+/// the hash the caller last loaded (i.e. it was modified externally since then)
+#[tauri::command]
+fn save_journal_checked(
+    filename: String,
+    content: String,
+    directory: String,
+    expected_hash: String,
+    state: tauri::State<Mutex<Settings>>,
+) -> Result<SaveOutcome, String> {
+    let path = PathBuf::from(&directory).join(&filename);
+    if path.exists() {
+        let on_disk = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read journal entry: {}", e))?;
+        let current_hash = content_hash(on_disk.as_bytes());
+        if current_hash != expected_hash {
+            return Ok(SaveOutcome::Conflict {
+                current_content: on_disk,
+                current_hash,
+            });
+        }
+    }
+
+    save_journal(filename, content, directory, state)?;
+    Ok(SaveOutcome::Saved)
+}
+
 /// Delete a journal entry
 #[tauri::command]
-fn delete_journal(filename: String, directory: String) -> Result<(), String> {
+fn delete_journal(
+    filename: String,
+    directory: String,
+    state: tauri::State<Mutex<Settings>>,
+) -> Result<(), String> {
     let path = PathBuf::from(&directory).join(&filename);
     if path.exists() {
         fs::remove_file(&path)
-            .map_err(|e| format!("Failed to delete journal entry: {}", e))
+            .map_err(|e| format!("Failed to delete journal entry: {}", e))?;
+
+        let git_enabled = state.lock().unwrap().git_enabled.unwrap_or(false);
+        if git_enabled {
+            commit_journal_change(&directory, &filename, &format!("delete {}", filename))?;
+        }
+
+        Ok(())
     } else {
         Err("File does not exist".to_string())
     }
 }
 
+/// Get the commit history for a journal entry. An entry that predates git being enabled
+/// (no `.git` yet, or a repo with no commits yet) simply has no history, not an error.
+#[tauri::command]
+fn journal_history(filename: String, directory: String) -> Result<Vec<CommitInfo>, String> {
+    if !PathBuf::from(&directory).join(".git").exists() {
+        return Ok(Vec::new());
+    }
+
+    let output = run_git(
+        &directory,
+        &["log", "--format=%H%x00%cI%x00%s", "--", &filename],
+    )?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("does not have any commits yet") {
+            return Ok(Vec::new());
+        }
+        return Err(stderr.to_string());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let history = stdout
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\0');
+            Some(CommitInfo {
+                hash: fields.next()?.to_string(),
+                date: fields.next()?.to_string(),
+                message: fields.next()?.to_string(),
+            })
+        })
+        .collect();
+    Ok(history)
+}
+
+/// Load a journal entry's content as of a specific revision
+#[tauri::command]
+fn journal_at_revision(
+    filename: String,
+    directory: String,
+    hash: String,
+) -> Result<String, String> {
+    let output = run_git(&directory, &["show", &format!("{}:{}", hash, filename)])?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Render `date` with a chrono strftime `format` without panicking on an invalid pattern.
+/// `NaiveDate::format`'s `Display` impl returns `fmt::Error` for a malformed format string,
+/// and the stdlib's blanket `ToString::to_string()` would `.expect()` that into a panic —
+/// going through `write!` lets us observe and handle the error instead.
+fn try_format_date(date: &NaiveDate, format: &str) -> Result<String, ()> {
+    use std::fmt::Write;
+    let mut buf = String::new();
+    write!(buf, "{}", date.format(format)).map(|_| buf).map_err(|_| ())
+}
+
+/// Format a `YYYY-MM-DD` date string as a daily entry's display title, using the
+/// configured `daily_filename_format` (a chrono strftime string) when set
+fn format_daily_title(date: &str, daily_filename_format: Option<&str>) -> String {
+    match daily_filename_format {
+        Some(format) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .ok()
+            .and_then(|d| try_format_date(&d, format).ok())
+            .unwrap_or_else(|| date.to_string()),
+        None => date.to_string(),
+    }
+}
+
 /// List all journal entries in the directory
 #[tauri::command]
-fn list_journal_entries(directory: String) -> Result<Vec<JournalEntry>, String> {
+fn list_journal_entries(
+    directory: String,
+    state: tauri::State<Mutex<Settings>>,
+) -> Result<Vec<JournalEntry>, String> {
+    let daily_filename_format = state.lock().unwrap().daily_filename_format.clone();
     let dir_path = PathBuf::from(&directory);
     let mut entries: Vec<JournalEntry> = fs::read_dir(&dir_path)
         .map_err(|e| format!("Failed to read directory: {}", e))?
@@ -104,14 +443,21 @@ fn list_journal_entries(directory: String) -> Result<Vec<JournalEntry>, String>
                 return None;
             }
 
+            let front_matter = read_front_matter(&entry.path());
+            let metadata = front_matter.metadata_as_strings();
+
             if is_daily_entry(&file_name) {
                 // Daily entry: YYYY-MM-DD.md
                 let date = file_name[..10].to_string();
+                let title = format_daily_title(&date, daily_filename_format.as_deref());
                 Some(JournalEntry {
                     filename: file_name,
                     entry_type: "daily".to_string(),
-                    title: date.clone(),
+                    title,
                     date: Some(date),
+                    tags: front_matter.tags,
+                    created: front_matter.created,
+                    metadata,
                 })
             } else {
                 // Titled entry: anything else ending in .md
@@ -121,6 +467,9 @@ fn list_journal_entries(directory: String) -> Result<Vec<JournalEntry>, String>
                     entry_type: "titled".to_string(),
                     title,
                     date: None,
+                    tags: front_matter.tags,
+                    created: front_matter.created,
+                    metadata,
                 })
             }
         })
@@ -139,48 +488,274 @@ fn list_journal_entries(directory: String) -> Result<Vec<JournalEntry>, String>
     Ok(entries)
 }
 
-/// Get the saved journal directory
+/// List journal entries that carry a given tag in their front matter
 #[tauri::command]
-fn get_journal_directory() -> Option<String> {
-    load_settings().journal_directory
+fn list_journal_entries_by_tag(
+    directory: String,
+    tag: String,
+    state: tauri::State<Mutex<Settings>>,
+) -> Result<Vec<JournalEntry>, String> {
+    let entries = list_journal_entries(directory, state)?;
+    Ok(entries.into_iter().filter(|e| e.tags.contains(&tag)).collect())
+}
+
+/// List all tags in use across the journal directory, with their entry counts
+#[tauri::command]
+fn all_tags(
+    directory: String,
+    state: tauri::State<Mutex<Settings>>,
+) -> Result<Vec<(String, usize)>, String> {
+    let entries = list_journal_entries(directory, state)?;
+    let mut counts: std::collections::BTreeMap<String, usize> = std::collections::BTreeMap::new();
+    for entry in entries {
+        for tag in entry.tags {
+            *counts.entry(tag).or_insert(0) += 1;
+        }
+    }
+    Ok(counts.into_iter().collect())
+}
+
+/// Get the templates directory for a journal directory
+fn templates_dir(directory: &str) -> PathBuf {
+    PathBuf::from(directory).join("templates")
+}
+
+/// Resolve the weekday name (e.g. "Monday") for a YYYY-MM-DD date string
+fn weekday_name(date: &str) -> String {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|d| d.format("%A").to_string())
+        .unwrap_or_default()
+}
+
+/// Build the `{{date}}`/`{{time}}`/`{{title}}`/`{{weekday}}` substitution context for a new entry
+fn build_template_context(
+    filename: &str,
+    title: &str,
+    hour_format: Option<&str>,
+) -> HashMap<String, String> {
+    let date = if is_daily_entry(filename) {
+        filename[..10].to_string()
+    } else {
+        String::new()
+    };
+
+    let time_format = match hour_format {
+        Some("12h") => "%I:%M %p",
+        _ => "%H:%M",
+    };
+
+    let mut context = HashMap::new();
+    context.insert("weekday".to_string(), weekday_name(&date));
+    context.insert("date".to_string(), date);
+    context.insert("time".to_string(), Local::now().format(time_format).to_string());
+    context.insert("title".to_string(), title.to_string());
+    context
+}
+
+/// Render `{{token}}` placeholders in a template against a substitution context.
+/// A literal `{{` can be preserved by doubling it (`{{{{`).
+fn render_template(template: &str, context: &HashMap<String, String>) -> String {
+    const LITERAL_BRACE_SENTINEL: &str = "\u{0}ILLIEN_LITERAL_BRACE\u{0}";
+
+    let escaped = template.replace("{{{{", LITERAL_BRACE_SENTINEL);
+
+    let mut rendered = escaped;
+    for (key, value) in context {
+        rendered = rendered.replace(&format!("{{{{{}}}}}", key), value);
+    }
+
+    rendered.replace(LITERAL_BRACE_SENTINEL, "{{")
+}
+
+/// List the available template names (without the `.md` extension) for a journal directory
+#[tauri::command]
+fn list_templates(directory: String) -> Result<Vec<String>, String> {
+    let dir_path = templates_dir(&directory);
+    if !dir_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut names: Vec<String> = fs::read_dir(&dir_path)
+        .map_err(|e| format!("Failed to read templates directory: {}", e))?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            entry
+                .file_name()
+                .to_string_lossy()
+                .strip_suffix(".md")
+                .map(|name| name.to_string())
+        })
+        .collect();
+    names.sort();
+    Ok(names)
 }
 
-/// Save the journal directory setting
+/// Create a new journal entry from a template, substituting its placeholder tokens
 #[tauri::command]
-fn set_journal_directory(directory: String) -> Result<(), String> {
-    let mut settings = load_settings();
-    settings.journal_directory = Some(directory);
+fn create_journal_from_template(
+    filename: String,
+    template_name: String,
+    directory: String,
+    state: tauri::State<Mutex<Settings>>,
+) -> Result<(), String> {
+    let template_path = templates_dir(&directory).join(format!("{}.md", template_name));
+    let template = fs::read_to_string(&template_path)
+        .map_err(|e| format!("Failed to load template: {}", e))?;
+
+    let title = filename.trim_end_matches(".md");
+    let hour_format = state.lock().unwrap().hour_format.clone();
+    let context = build_template_context(&filename, title, hour_format.as_deref());
+    let content = render_template(&template, &context);
+
+    save_journal(filename, content, directory, state)
+}
+
+/// Get the raw journal directory setting (mode + stored path), as opposed to
+/// `resolve_journal_directory`'s resolved absolute path
+#[tauri::command]
+fn get_journal_directory_setting(state: tauri::State<Mutex<Settings>>) -> JournalDirectorySetting {
+    let settings = state.lock().unwrap();
+    JournalDirectorySetting {
+        mode: settings.journal_directory_mode.clone(),
+        path: settings.journal_directory_path.clone(),
+    }
+}
+
+/// Resolve the journal directory: the stored path in "custom" mode, or the user's
+/// home/documents directory resolved fresh in "home" mode
+#[tauri::command]
+fn resolve_journal_directory(state: tauri::State<Mutex<Settings>>) -> Result<String, String> {
+    let settings = state.lock().unwrap();
+    match settings.journal_directory_mode {
+        JournalDirectoryMode::Home => dirs::document_dir()
+            .or_else(dirs::home_dir)
+            .map(|dir| dir.join("illien").to_string_lossy().to_string())
+            .ok_or_else(|| "Failed to resolve home directory".to_string()),
+        JournalDirectoryMode::Custom => settings
+            .journal_directory_path
+            .clone()
+            .ok_or_else(|| "No journal directory configured".to_string()),
+    }
+}
+
+/// Save the journal directory setting. Pass `"home"` to follow the user's home/documents
+/// directory across machines, or any other path to pin an explicit custom directory.
+#[tauri::command]
+fn set_journal_directory(
+    directory: String,
+    state: tauri::State<Mutex<Settings>>,
+) -> Result<(), String> {
+    let mut settings = state.lock().unwrap();
+    if directory == "home" {
+        settings.journal_directory_mode = JournalDirectoryMode::Home;
+        settings.journal_directory_path = None;
+    } else {
+        settings.journal_directory_mode = JournalDirectoryMode::Custom;
+        settings.journal_directory_path = Some(directory);
+    }
     save_settings(&settings)
 }
 
 /// Get the dark mode preference
 #[tauri::command]
-fn get_dark_mode() -> Option<bool> {
-    load_settings().dark_mode
+fn get_dark_mode(state: tauri::State<Mutex<Settings>>) -> Option<bool> {
+    state.lock().unwrap().dark_mode
 }
 
 /// Save the dark mode preference
 #[tauri::command]
-fn set_dark_mode(dark_mode: bool) -> Result<(), String> {
-    let mut settings = load_settings();
+fn set_dark_mode(dark_mode: bool, state: tauri::State<Mutex<Settings>>) -> Result<(), String> {
+    let mut settings = state.lock().unwrap();
     settings.dark_mode = Some(dark_mode);
     save_settings(&settings)
 }
 
+/// Get whether git-backed version history is enabled
+#[tauri::command]
+fn get_git_enabled(state: tauri::State<Mutex<Settings>>) -> Option<bool> {
+    state.lock().unwrap().git_enabled
+}
+
+/// Save the git-backed version history preference
+#[tauri::command]
+fn set_git_enabled(git_enabled: bool, state: tauri::State<Mutex<Settings>>) -> Result<(), String> {
+    let mut settings = state.lock().unwrap();
+    settings.git_enabled = Some(git_enabled);
+    save_settings(&settings)
+}
+
+/// Get the preferred clock format ("12h" or "24h")
+#[tauri::command]
+fn get_hour_format(state: tauri::State<Mutex<Settings>>) -> Option<String> {
+    state.lock().unwrap().hour_format.clone()
+}
+
+/// Save the preferred clock format ("12h" or "24h")
+#[tauri::command]
+fn set_hour_format(
+    hour_format: String,
+    state: tauri::State<Mutex<Settings>>,
+) -> Result<(), String> {
+    let mut settings = state.lock().unwrap();
+    settings.hour_format = Some(hour_format);
+    save_settings(&settings)
+}
+
+/// Get the strftime-style format used for the daily entry title
+#[tauri::command]
+fn get_daily_filename_format(state: tauri::State<Mutex<Settings>>) -> Option<String> {
+    state.lock().unwrap().daily_filename_format.clone()
+}
+
+/// Save the strftime-style format used for the daily entry title. Rejected up front if it
+/// doesn't render against a sample date, so a bad pattern can't reach `list_journal_entries`.
+#[tauri::command]
+fn set_daily_filename_format(
+    daily_filename_format: String,
+    state: tauri::State<Mutex<Settings>>,
+) -> Result<(), String> {
+    let sample_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    if try_format_date(&sample_date, &daily_filename_format).is_err() {
+        return Err("Invalid date format string".to_string());
+    }
+
+    let mut settings = state.lock().unwrap();
+    settings.daily_filename_format = Some(daily_filename_format);
+    save_settings(&settings)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
+        .setup(|app| {
+            app.manage(Mutex::new(load_settings()));
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             save_journal,
             load_journal,
             delete_journal,
             list_journal_entries,
-            get_journal_directory,
             set_journal_directory,
             get_dark_mode,
-            set_dark_mode
+            set_dark_mode,
+            list_templates,
+            create_journal_from_template,
+            get_git_enabled,
+            set_git_enabled,
+            journal_history,
+            journal_at_revision,
+            list_journal_entries_by_tag,
+            all_tags,
+            get_journal_directory_setting,
+            resolve_journal_directory,
+            get_hour_format,
+            set_hour_format,
+            get_daily_filename_format,
+            set_daily_filename_format,
+            save_journal_checked
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");